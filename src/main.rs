@@ -1,127 +1,366 @@
+use argh::FromArgs;
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::diagnostic::LogDiagnosticsPlugin;
 use bevy::prelude::*;
-use rand::Rng;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::window::{PresentMode, PrimaryWindow, WindowPlugin, WindowResized};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::str::FromStr;
+use std::time::Duration;
+
+mod patterns;
+
+/// Where a saved snapshot (`F5`/`F9`) is read from and written to.
+const SNAPSHOT_PATH: &str = "snapshot.json";
+
+/// RLE for the classic Gosper glider gun, used as the `glider-gun` pattern.
+const GOSPER_GLIDER_GUN_RLE: &str = "\
+x = 36, y = 9, rule = B3/S23
+24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4b
+obo$10bo5bo7bo$11bo3bo$12b2o!
+";
 
 const GRID_WIDTH: usize = 300;
 const GRID_HEIGHT: usize = GRID_WIDTH * 9 / 16;
 const CELL_SIZE: f32 = 3.0;
 
+// How much +/- nudges the simulation interval per key press.
+const SIM_SPEED_STEP: Duration = Duration::from_millis(5);
+const MIN_SIM_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A flat `width * height` buffer of cell states, indexed row-major.
+///
+/// Flat storage (rather than a const-generic `[[bool; W]; H]`) lets the grid
+/// grow or shrink at runtime via `resize` without a recompile.
 #[derive(Resource)]
-struct Grid([[bool; GRID_WIDTH]; GRID_HEIGHT]);
+pub(crate) struct Grid {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) cells: Vec<bool>,
+    /// Generations since each cell last changed state: while alive, how long
+    /// it's been continuously alive; while dead, how long it's been dead
+    /// (used to fade out a just-died cell's trail).
+    pub(crate) ages: Vec<u16>,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![false; width * height],
+            // Untouched dead cells start at max age so `ColorScheme::color_for`
+            // renders them as the settled background color immediately,
+            // rather than fading in from "just died" over `COLOR_RAMP_LENGTH`
+            // generations.
+            ages: vec![u16::MAX; width * height],
+        }
+    }
+
+    pub(crate) fn get(&self, row: usize, col: usize) -> bool {
+        self.cells[row * self.width + col]
+    }
+
+    pub(crate) fn age(&self, row: usize, col: usize) -> u16 {
+        self.ages[row * self.width + col]
+    }
+
+    pub(crate) fn set(&mut self, row: usize, col: usize, alive: bool) {
+        let idx = row * self.width + col;
+        if self.cells[idx] != alive {
+            self.ages[idx] = 0;
+        }
+        self.cells[idx] = alive;
+    }
+
+    /// Grows or shrinks the grid to `new_width x new_height`, preserving
+    /// existing live cells. Growing appends dead cells at the high column and
+    /// row indices (the right and, since row 0 is the bottom of the world,
+    /// the top); shrinking truncates them from the same edges.
+    pub(crate) fn resize(&mut self, new_width: usize, new_height: usize) {
+        if new_width == self.width && new_height == self.height {
+            return;
+        }
+
+        let mut new_cells = vec![false; new_width * new_height];
+        // Same reasoning as `Grid::new`: newly-grown area starts at max age
+        // so it renders as background instead of "just died".
+        let mut new_ages = vec![u16::MAX; new_width * new_height];
+        let copy_width = self.width.min(new_width);
+        let copy_height = self.height.min(new_height);
+
+        for row in 0..copy_height {
+            for col in 0..copy_width {
+                new_cells[row * new_width + col] = self.get(row, col);
+                new_ages[row * new_width + col] = self.age(row, col);
+            }
+        }
 
+        self.width = new_width;
+        self.height = new_height;
+        self.cells = new_cells;
+        self.ages = new_ages;
+    }
+}
+
+/// Marker for the single sprite the grid is rendered into.
 #[derive(Component)]
-struct Cell {
-    row: usize,
-    col: usize,
+struct GridSprite;
+
+/// Handle to the `Image` asset backing `GridSprite`, written to directly
+/// each frame rather than spawning one entity per cell.
+#[derive(Resource)]
+struct GridTexture(Handle<Image>);
+
+/// Whether the simulation is advancing on its own.
+#[derive(Resource, Default)]
+struct SimState {
+    paused: bool,
 }
 
-fn setup(mut commands: Commands, mut grid: ResMut<Grid>) {
-    commands.spawn(Camera2dBundle::default());
+/// How `compute_next_generation` treats neighbors that fall outside the
+/// grid.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default)]
+enum BoundaryMode {
+    /// Out-of-bounds neighbors simply don't count, giving a dead border.
+    #[default]
+    Dead,
+    /// Out-of-bounds neighbors wrap to the opposite edge.
+    Toroidal,
+}
 
-    let mut rng = rand::thread_rng();
-    for i in 0..grid.0.len() {
-        for j in 0..grid.0[i].len() {
-            grid.0[i][j] = rng.gen_bool(0.1);
-        }
-    }
-
-    grid.0 = [[false; GRID_WIDTH]; GRID_HEIGHT];
-
-    // glider gun
-    let positions = vec![
-        (5, 1),
-        (5, 2),
-        (6, 1),
-        (6, 2),
-        (5, 11),
-        (6, 11),
-        (7, 11),
-        (4, 12),
-        (3, 13),
-        (3, 14),
-        (8, 12),
-        (9, 13),
-        (9, 14),
-        (6, 15),
-        (4, 16),
-        (5, 17),
-        (6, 17),
-        (7, 17),
-        (6, 18),
-        (8, 16),
-        (3, 21),
-        (4, 21),
-        (5, 21),
-        (3, 22),
-        (4, 22),
-        (5, 22),
-        (2, 23),
-        (6, 23),
-        (1, 25),
-        (2, 25),
-        (6, 25),
-        (7, 25),
-        (3, 35),
-        (4, 35),
-        (3, 36),
-        (4, 36),
-    ];
-
-    for (row, col) in positions {
-        grid.0[row][col] = true;
-    }
-}
-
-fn render_cells(mut commands: Commands, grid: ResMut<Grid>) {
-    for row in 0..grid.0.len() {
-        for col in 0..grid.0[0].len() {
-            // compute position, size and color
-            let position = Vec3::new(
-                (col as f32 - grid.0[0].len() as f32 / 2.0) * CELL_SIZE,
-                (row as f32 - grid.0.len() as f32 / 2.0) * CELL_SIZE,
-                0.0,
-            );
-
-            let size = Vec2::new(CELL_SIZE, CELL_SIZE);
-
-            let color = if grid.0[row][col] {
-                Color::BLACK
-            } else {
-                Color::WHITE
-            };
+impl BoundaryMode {
+    fn toggled(self) -> Self {
+        match self {
+            BoundaryMode::Dead => BoundaryMode::Toroidal,
+            BoundaryMode::Toroidal => BoundaryMode::Dead,
+        }
+    }
+}
 
-            let spritebundle = SpriteBundle {
-                sprite: Sprite {
-                    color,
-                    custom_size: Some(size),
-                    ..default()
-                },
-                transform: Transform::from_translation(position),
-                ..default()
-            };
+/// How long (in generations) a cell takes to fade from newborn to its
+/// settled color, and from settled to the background color after it dies.
+const COLOR_RAMP_LENGTH: u16 = 24;
+
+/// A color ramp mapping a cell's age to a color, so freshly-born cells are
+/// bright, long-stable structures settle into a duller hue, and cells that
+/// just died leave a decaying trail rather than vanishing instantly.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default)]
+enum ColorScheme {
+    #[default]
+    Classic,
+    Thermal,
+    Ocean,
+}
 
-            let cell = Cell { row, col };
+impl ColorScheme {
+    fn next(self) -> Self {
+        match self {
+            ColorScheme::Classic => ColorScheme::Thermal,
+            ColorScheme::Thermal => ColorScheme::Ocean,
+            ColorScheme::Ocean => ColorScheme::Classic,
+        }
+    }
 
-            // spawn cell in world
-            commands.spawn((cell, spritebundle));
+    /// `[newborn, settled]` colors for a live cell, and the background color
+    /// a dead cell's trail fades into.
+    fn stops(self) -> ([u8; 3], [u8; 3], [u8; 3]) {
+        match self {
+            ColorScheme::Classic => ([255, 221, 0], [20, 20, 20], [255, 255, 255]),
+            ColorScheme::Thermal => ([255, 255, 255], [178, 24, 24], [12, 12, 32]),
+            ColorScheme::Ocean => ([214, 255, 255], [8, 64, 128], [245, 247, 250]),
+        }
+    }
+
+    /// Maps a cell's `(alive, age)` to an RGB color under this scheme.
+    fn color_for(self, alive: bool, age: u16) -> [u8; 3] {
+        let (newborn, settled, background) = self.stops();
+
+        if alive {
+            lerp_color(
+                newborn,
+                settled,
+                age.min(COLOR_RAMP_LENGTH),
+                COLOR_RAMP_LENGTH,
+            )
+        } else {
+            lerp_color(
+                settled,
+                background,
+                age.min(COLOR_RAMP_LENGTH),
+                COLOR_RAMP_LENGTH,
+            )
         }
     }
 }
 
-fn compute_next_generation(mut grid: ResMut<Grid>) {
-    // update grid resource with next generation
+fn lerp_color(from: [u8; 3], to: [u8; 3], step: u16, steps: u16) -> [u8; 3] {
+    let t = step as f32 / steps as f32;
+    std::array::from_fn(|i| (from[i] as f32 + (to[i] as f32 - from[i] as f32) * t).round() as u8)
+}
 
-    // copy grid
-    let mut new_grid = [[false; GRID_WIDTH]; GRID_HEIGHT];
-    for row in 0..grid.0.len() {
-        for col in 0..grid.0[0].len() {
-            new_grid[row][col] = grid.0[row][col];
+/// Drives how often `compute_next_generation` runs while unpaused.
+#[derive(Resource)]
+struct SimSpeed {
+    timer: Timer,
+}
+
+impl Default for SimSpeed {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(Duration::from_millis(33), TimerMode::Repeating),
+        }
+    }
+}
+
+impl SimSpeed {
+    fn with_interval(interval_ms: u64) -> Self {
+        Self {
+            timer: Timer::new(Duration::from_millis(interval_ms), TimerMode::Repeating),
+        }
+    }
+}
+
+/// Which seed pattern to stamp into the grid on startup.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StartPattern {
+    Random,
+    GliderGun,
+    Empty,
+}
+
+impl FromStr for StartPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "random" => Ok(StartPattern::Random),
+            "glider-gun" => Ok(StartPattern::GliderGun),
+            "empty" => Ok(StartPattern::Empty),
+            other => Err(format!(
+                "unknown pattern `{other}` (expected `random`, `glider-gun`, or `empty`)"
+            )),
+        }
+    }
+}
+
+/// Conway's Game of Life, implemented with Bevy.
+#[derive(Resource, FromArgs)]
+struct Args {
+    /// initial grid width in cells, floored at 1 (the window opens sized to
+    /// fit this and `--height`; resizing the window afterward resizes the
+    /// grid to match)
+    #[argh(option, default = "GRID_WIDTH")]
+    width: usize,
+
+    /// initial grid height in cells, floored at 1 (see `--width`)
+    #[argh(option, default = "GRID_HEIGHT")]
+    height: usize,
+
+    /// fraction of cells alive at startup when using the `random` pattern
+    #[argh(option, default = "0.1")]
+    density: f64,
+
+    /// starting pattern: `random`, `glider-gun`, or `empty`
+    #[argh(option, default = "StartPattern::GliderGun")]
+    pattern: StartPattern,
+
+    /// path to an external `.rle` or `.cells` pattern file to load at
+    /// startup, centered on the grid (overrides `--pattern`)
+    #[argh(option)]
+    pattern_file: Option<String>,
+
+    /// RNG seed, for a reproducible `random` starting pattern
+    #[argh(option, default = "0")]
+    seed: u64,
+
+    /// milliseconds between simulation steps
+    #[argh(option, default = "33")]
+    tick_rate: u64,
+
+    /// enable vsync
+    #[argh(switch)]
+    vsync: bool,
+}
+
+fn setup(mut commands: Commands, mut grid: ResMut<Grid>, args: Res<Args>) {
+    commands.spawn(Camera2dBundle::default());
+
+    if let Some(path) = &args.pattern_file {
+        match patterns::load_file(path) {
+            Ok(pattern) => {
+                let origin_row = grid.height.saturating_sub(pattern.height) / 2;
+                let origin_col = grid.width.saturating_sub(pattern.width) / 2;
+                patterns::stamp(&mut grid, &pattern, origin_row, origin_col);
+            }
+            Err(err) => error!("failed to load pattern file {path}: {err}"),
+        }
+        return;
+    }
+
+    match args.pattern {
+        StartPattern::Empty => {}
+        StartPattern::Random => {
+            let mut rng = StdRng::seed_from_u64(args.seed);
+            for row in 0..grid.height {
+                for col in 0..grid.width {
+                    let alive = rng.gen_bool(args.density);
+                    grid.set(row, col, alive);
+                }
+            }
+        }
+        StartPattern::GliderGun => {
+            let pattern = patterns::parse_rle(GOSPER_GLIDER_GUN_RLE)
+                .expect("GOSPER_GLIDER_GUN_RLE is a valid RLE pattern");
+            patterns::stamp(&mut grid, &pattern, 0, 0);
         }
     }
+}
+
+fn render_cells(mut commands: Commands, grid: Res<Grid>, mut images: ResMut<Assets<Image>>) {
+    let size = Extent3d {
+        width: grid.width as u32,
+        height: grid.height as u32,
+        depth_or_array_layers: 1,
+    };
 
-    for row in 0..grid.0.len() {
-        for col in 0..grid.0[0].len() {
+    let image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+
+    let texture = images.add(image);
+
+    commands.spawn((
+        GridSprite,
+        SpriteBundle {
+            texture: texture.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(
+                    grid.width as f32 * CELL_SIZE,
+                    grid.height as f32 * CELL_SIZE,
+                )),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+
+    commands.insert_resource(GridTexture(texture));
+}
+
+fn compute_next_generation(grid: &mut Grid, boundary_mode: BoundaryMode) {
+    // update grid resource with next generation
+
+    // copy grid
+    let mut new_cells = grid.cells.clone();
+    let mut new_ages = grid.ages.clone();
+
+    for row in 0..grid.height {
+        for col in 0..grid.width {
             let mut count = 0;
 
             for delta_row in -1..=1 {
@@ -131,61 +370,288 @@ fn compute_next_generation(mut grid: ResMut<Grid>) {
                     }
                     let new_row = row as i32 + delta_row;
                     let new_col = col as i32 + delta_col;
-                    if new_row < 0 || new_row >= grid.0.len() as i32 {
-                        continue;
-                    }
-                    if new_col < 0 || new_col >= grid.0[0].len() as i32 {
-                        continue;
-                    }
-                    if grid.0[new_row as usize][new_col as usize] {
+
+                    let (new_row, new_col) = match boundary_mode {
+                        BoundaryMode::Dead => {
+                            if new_row < 0
+                                || new_row >= grid.height as i32
+                                || new_col < 0
+                                || new_col >= grid.width as i32
+                            {
+                                continue;
+                            }
+                            (new_row as usize, new_col as usize)
+                        }
+                        BoundaryMode::Toroidal => (
+                            new_row.rem_euclid(grid.height as i32) as usize,
+                            new_col.rem_euclid(grid.width as i32) as usize,
+                        ),
+                    };
+
+                    if grid.get(new_row, new_col) {
                         count += 1;
                     }
                 }
             }
 
-            if grid.0[row][col] {
-                if count < 2 || count > 3 {
-                    new_grid[row][col] = false;
-                }
+            let idx = row * grid.width + col;
+            let was_alive = grid.get(row, col);
+            let alive = if was_alive {
+                !(count < 2 || count > 3)
             } else {
-                if count == 3 {
-                    new_grid[row][col] = true;
-                }
-            }
+                count == 3
+            };
+
+            new_cells[idx] = alive;
+            new_ages[idx] = if alive == was_alive {
+                grid.ages[idx].saturating_add(1)
+            } else {
+                0
+            };
         }
     }
 
-    grid.0 = new_grid;
+    grid.cells = new_cells;
+    grid.ages = new_ages;
 }
 
-fn update_cell_color(mut query: Query<(&Cell, &mut Sprite)>, grid: Res<Grid>) {
-    for (cell, mut sprite) in query.iter_mut() {
-        if grid.0[cell.row][cell.col] {
-            sprite.color = Color::BLACK;
-        } else {
-            sprite.color = Color::WHITE;
+/// Writes the current cell states directly into the grid texture's pixel
+/// buffer, resizing it first if the grid's dimensions have changed.
+fn update_grid_texture(
+    grid: Res<Grid>,
+    grid_texture: Res<GridTexture>,
+    color_scheme: Res<ColorScheme>,
+    mut images: ResMut<Assets<Image>>,
+    mut sprites: Query<&mut Sprite, With<GridSprite>>,
+) {
+    let Some(image) = images.get_mut(&grid_texture.0) else {
+        return;
+    };
+
+    let size = Extent3d {
+        width: grid.width as u32,
+        height: grid.height as u32,
+        depth_or_array_layers: 1,
+    };
+
+    if image.texture_descriptor.size != size {
+        image.resize(size);
+
+        if let Ok(mut sprite) = sprites.get_single_mut() {
+            sprite.custom_size = Some(Vec2::new(
+                grid.width as f32 * CELL_SIZE,
+                grid.height as f32 * CELL_SIZE,
+            ));
+        }
+    }
+
+    for row in 0..grid.height {
+        for col in 0..grid.width {
+            let [r, g, b] = color_scheme.color_for(grid.get(row, col), grid.age(row, col));
+
+            // Image data is stored top-down, but grid row 0 is the bottom of
+            // the world (see `world_pos_to_cell`'s centering math), so flip rows.
+            let image_row = grid.height - 1 - row;
+            let idx = (image_row * grid.width + col) * 4;
+            image.data[idx..idx + 4].copy_from_slice(&[r, g, b, 255]);
         }
     }
 }
 
-fn wait() {
-    std::thread::sleep(std::time::Duration::from_millis(33));
+/// Inverts the centering math in `render_cells` to map a cursor's world
+/// position back to a `(row, col)` cell, if it falls on the grid.
+fn world_pos_to_cell(world_pos: Vec2, width: usize, height: usize) -> Option<(usize, usize)> {
+    let col = (world_pos.x / CELL_SIZE + width as f32 / 2.0).floor();
+    let row = (world_pos.y / CELL_SIZE + height as f32 / 2.0).floor();
+
+    if row < 0.0 || col < 0.0 {
+        return None;
+    }
+
+    let (row, col) = (row as usize, col as usize);
+    if row >= height || col >= width {
+        return None;
+    }
+
+    Some((row, col))
+}
+
+fn handle_mouse_input(
+    mut grid: ResMut<Grid>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    let alive = if mouse_buttons.pressed(MouseButton::Left) {
+        true
+    } else if mouse_buttons.pressed(MouseButton::Right) {
+        false
+    } else {
+        return;
+    };
+
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.get_single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let Some(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) else {
+        return;
+    };
+
+    if let Some((row, col)) = world_pos_to_cell(world_pos, grid.width, grid.height) {
+        grid.set(row, col, alive);
+    }
+}
+
+/// `F5` saves the live cells to `SNAPSHOT_PATH` as JSON; `F9` reloads them.
+fn handle_pattern_io_input(keyboard: Res<Input<KeyCode>>, mut grid: ResMut<Grid>) {
+    if keyboard.just_pressed(KeyCode::F5) {
+        match patterns::Snapshot::from_grid(&grid).save(SNAPSHOT_PATH) {
+            Ok(()) => info!("saved snapshot to {SNAPSHOT_PATH}"),
+            Err(err) => error!("failed to save snapshot to {SNAPSHOT_PATH}: {err}"),
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::F9) {
+        match patterns::Snapshot::load(SNAPSHOT_PATH) {
+            Ok(snapshot) => snapshot.apply_to(&mut grid),
+            Err(err) => error!("failed to load snapshot from {SNAPSHOT_PATH}: {err}"),
+        }
+    }
+}
+
+/// `C` cycles through the available cell-age color schemes.
+fn handle_color_scheme_input(keyboard: Res<Input<KeyCode>>, mut color_scheme: ResMut<ColorScheme>) {
+    if keyboard.just_pressed(KeyCode::C) {
+        *color_scheme = color_scheme.next();
+    }
+}
+
+/// Keeps the grid's dimensions in sync with the window, so the board always
+/// fills it.
+fn handle_window_resize(mut resize_events: EventReader<WindowResized>, mut grid: ResMut<Grid>) {
+    for event in resize_events.read() {
+        let new_width = ((event.width / CELL_SIZE).floor() as usize).max(1);
+        let new_height = ((event.height / CELL_SIZE).floor() as usize).max(1);
+        grid.resize(new_width, new_height);
+    }
+}
+
+/// Space toggles pause; while paused, Right arrow steps a single generation.
+/// T toggles between dead and toroidal (wrap-around) boundaries.
+fn handle_sim_state_input(
+    keyboard: Res<Input<KeyCode>>,
+    mut sim_state: ResMut<SimState>,
+    mut grid: ResMut<Grid>,
+    mut boundary_mode: ResMut<BoundaryMode>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        sim_state.paused = !sim_state.paused;
+    }
+
+    if keyboard.just_pressed(KeyCode::T) {
+        *boundary_mode = boundary_mode.toggled();
+    }
+
+    if sim_state.paused && keyboard.just_pressed(KeyCode::Right) {
+        compute_next_generation(&mut grid, *boundary_mode);
+    }
+}
+
+/// `=`/`-` speed the simulation up or down by nudging the step interval.
+fn handle_sim_speed_input(keyboard: Res<Input<KeyCode>>, mut sim_speed: ResMut<SimSpeed>) {
+    if keyboard.just_pressed(KeyCode::Equals) {
+        let interval = sim_speed
+            .timer
+            .duration()
+            .saturating_sub(SIM_SPEED_STEP)
+            .max(MIN_SIM_INTERVAL);
+        sim_speed.timer.set_duration(interval);
+    }
+
+    if keyboard.just_pressed(KeyCode::Minus) {
+        let interval = sim_speed.timer.duration() + SIM_SPEED_STEP;
+        sim_speed.timer.set_duration(interval);
+    }
+}
+
+fn step_simulation(
+    mut grid: ResMut<Grid>,
+    time: Res<Time>,
+    mut sim_speed: ResMut<SimSpeed>,
+    sim_state: Res<SimState>,
+    boundary_mode: Res<BoundaryMode>,
+) {
+    sim_speed.timer.tick(time.delta());
+
+    if sim_state.paused || !sim_speed.timer.just_finished() {
+        return;
+    }
+
+    compute_next_generation(&mut grid, *boundary_mode);
 }
 
 fn main() {
+    let mut args: Args = argh::from_env();
+    // Floor at 1, matching `handle_window_resize`'s clamp on window-derived
+    // dimensions, so `--width 0`/`--height 0` can't produce a zero-sized
+    // grid/texture/window at startup.
+    args.width = args.width.max(1);
+    args.height = args.height.max(1);
+
+    let present_mode = if args.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    let sim_speed = SimSpeed::with_interval(args.tick_rate);
+    // Open the window already sized to fit the requested grid, so the
+    // `WindowResized` event `handle_window_resize` reacts to on startup
+    // reproduces `args.width`/`args.height` instead of clobbering them with
+    // some unrelated default resolution.
+    let window_resolution = (
+        args.width as f32 * CELL_SIZE,
+        args.height as f32 * CELL_SIZE,
+    );
+
     App::new()
         .add_plugins((
-            DefaultPlugins,
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    present_mode,
+                    resolution: window_resolution.into(),
+                    ..default()
+                }),
+                ..default()
+            }),
             FrameTimeDiagnosticsPlugin::default(),
             LogDiagnosticsPlugin::default(),
         ))
-        .insert_resource(Grid {
-            0: [[false; GRID_WIDTH]; GRID_HEIGHT],
-        })
+        .insert_resource(Grid::new(args.width, args.height))
+        .insert_resource(args)
+        .insert_resource(SimState::default())
+        .insert_resource(sim_speed)
+        .insert_resource(BoundaryMode::default())
+        .insert_resource(ColorScheme::default())
         .add_systems(Startup, (setup, render_cells).chain())
         .add_systems(
             Update,
-            (compute_next_generation, update_cell_color, wait).chain(),
+            (
+                handle_window_resize,
+                handle_mouse_input,
+                handle_sim_state_input,
+                handle_sim_speed_input,
+                handle_pattern_io_input,
+                handle_color_scheme_input,
+                step_simulation,
+                update_grid_texture,
+            )
+                .chain(),
         )
         .run();
 }
@@ -0,0 +1,323 @@
+//! Loading and saving Game of Life patterns.
+//!
+//! Supports the two common community pattern formats (RLE and plaintext
+//! `.cells`) for stamping a pattern into a [`Grid`], plus JSON snapshots of
+//! the live grid for saving and reloading a user's own creations.
+
+use crate::Grid;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A decoded pattern: its declared bounding box and the `(row, col)` of each
+/// live cell within it, relative to the pattern's own top-left corner.
+pub struct Pattern {
+    pub width: usize,
+    pub height: usize,
+    pub live_cells: Vec<(usize, usize)>,
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    MissingHeader,
+    InvalidToken(char),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::MissingHeader => {
+                write!(f, "missing or malformed `x = .., y = ..` header")
+            }
+            PatternError::InvalidToken(c) => write!(f, "unexpected token `{c}` in pattern data"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Parses the standard Life RLE format: a header line `x = m, y = n` (an
+/// optional trailing `, rule = ...` is ignored), followed by run-length
+/// tokens where `b` is dead, `o` is alive, `$` ends a row, and `!` ends the
+/// pattern. A digit prefix on any token repeats it that many times.
+pub fn parse_rle(input: &str) -> Result<Pattern, PatternError> {
+    let mut width = 0;
+    let mut height = 0;
+    let mut header_found = false;
+    let mut live_cells = Vec::new();
+    let mut row = 0usize;
+    let mut col = 0usize;
+
+    'lines: for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if !header_found {
+            (width, height) = parse_rle_header(line)?;
+            header_found = true;
+            continue;
+        }
+
+        let mut count = 0usize;
+        for ch in line.chars() {
+            match ch {
+                '0'..='9' => count = count * 10 + ch.to_digit(10).unwrap() as usize,
+                'b' => {
+                    col += count.max(1);
+                    count = 0;
+                }
+                'o' => {
+                    for _ in 0..count.max(1) {
+                        live_cells.push((row, col));
+                        col += 1;
+                    }
+                    count = 0;
+                }
+                '$' => {
+                    row += count.max(1);
+                    col = 0;
+                    count = 0;
+                }
+                '!' => break 'lines,
+                _ => return Err(PatternError::InvalidToken(ch)),
+            }
+        }
+    }
+
+    if !header_found {
+        return Err(PatternError::MissingHeader);
+    }
+
+    Ok(Pattern {
+        width,
+        height,
+        live_cells,
+    })
+}
+
+fn parse_rle_header(line: &str) -> Result<(usize, usize), PatternError> {
+    let mut width = None;
+    let mut height = None;
+
+    for part in line.split(',') {
+        let part = part.trim();
+        if let Some(value) = part.strip_prefix("x =").or_else(|| part.strip_prefix("x=")) {
+            width = value.trim().parse().ok();
+        } else if let Some(value) = part.strip_prefix("y =").or_else(|| part.strip_prefix("y=")) {
+            height = value.trim().parse().ok();
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height)),
+        _ => Err(PatternError::MissingHeader),
+    }
+}
+
+/// Parses the plaintext `.cells` format: `.` is dead, `O` is alive, and
+/// lines starting with `!` are comments (ignored).
+pub fn parse_plaintext(input: &str) -> Result<Pattern, PatternError> {
+    let mut live_cells = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (row, line) in input
+        .lines()
+        .filter(|line| !line.starts_with('!'))
+        .enumerate()
+    {
+        width = width.max(line.len());
+        height = row + 1;
+
+        for (col, ch) in line.chars().enumerate() {
+            match ch {
+                'O' => live_cells.push((row, col)),
+                '.' => {}
+                other => return Err(PatternError::InvalidToken(other)),
+            }
+        }
+    }
+
+    Ok(Pattern {
+        width,
+        height,
+        live_cells,
+    })
+}
+
+/// Loads a pattern from an external file, dispatching to [`parse_rle`] or
+/// [`parse_plaintext`] based on its extension (`.rle` or `.cells`).
+pub fn load_file(path: &str) -> Result<Pattern, Box<dyn std::error::Error>> {
+    let input = std::fs::read_to_string(path)?;
+
+    match std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+    {
+        Some("rle") => Ok(parse_rle(&input)?),
+        Some("cells") => Ok(parse_plaintext(&input)?),
+        other => Err(format!(
+            "unrecognized pattern file extension {other:?} (expected `.rle` or `.cells`)"
+        )
+        .into()),
+    }
+}
+
+/// Stamps `pattern`'s live cells into `grid`, offset by `(origin_row,
+/// origin_col)`. Cells that land outside the grid are silently clipped.
+pub fn stamp(grid: &mut Grid, pattern: &Pattern, origin_row: usize, origin_col: usize) {
+    for &(row, col) in &pattern.live_cells {
+        let (row, col) = (origin_row + row, origin_col + col);
+        if row < grid.height && col < grid.width {
+            grid.set(row, col, true);
+        }
+    }
+}
+
+/// A JSON-serializable snapshot of a grid's live cells, for saving and
+/// reloading a user's own creations across runs.
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot {
+    pub width: usize,
+    pub height: usize,
+    pub live_cells: Vec<(usize, usize)>,
+}
+
+impl Snapshot {
+    pub fn from_grid(grid: &Grid) -> Self {
+        let mut live_cells = Vec::new();
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                if grid.get(row, col) {
+                    live_cells.push((row, col));
+                }
+            }
+        }
+
+        Self {
+            width: grid.width,
+            height: grid.height,
+            live_cells,
+        }
+    }
+
+    /// Resizes `grid` to the snapshot's dimensions and restores its cells.
+    pub fn apply_to(&self, grid: &mut Grid) {
+        grid.resize(self.width, self.height);
+        grid.cells.iter_mut().for_each(|cell| *cell = false);
+        // Reset ages alongside cells (matching `Grid::new`'s "untouched"
+        // initialization) rather than leaving stale ages behind for cells
+        // that were alive before the reload but aren't in the snapshot.
+        grid.ages.iter_mut().for_each(|age| *age = u16::MAX);
+
+        for &(row, col) in &self.live_cells {
+            if row < grid.height && col < grid.width {
+                grid.set(row, col, true);
+            }
+        }
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("Snapshot always serializes");
+        std::fs::write(path, json)
+    }
+
+    pub fn load(path: &str) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, GOSPER_GLIDER_GUN_RLE};
+
+    #[test]
+    fn parses_gosper_glider_gun_rle() {
+        let pattern = parse_rle(GOSPER_GLIDER_GUN_RLE).expect("valid RLE");
+        assert_eq!(pattern.width, 36);
+        assert_eq!(pattern.height, 9);
+        assert_eq!(pattern.live_cells.len(), 36);
+    }
+
+    #[test]
+    fn rejects_rle_missing_header() {
+        assert!(matches!(
+            parse_rle("bo$o!"),
+            Err(PatternError::MissingHeader)
+        ));
+    }
+
+    #[test]
+    fn parses_plaintext_glider() {
+        let input = "!Name: Glider\n.O.\n..O\nOOO\n";
+        let pattern = parse_plaintext(input).expect("valid plaintext");
+        assert_eq!(pattern.width, 3);
+        assert_eq!(pattern.height, 3);
+        assert_eq!(
+            pattern.live_cells,
+            vec![(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_plaintext_token() {
+        assert!(matches!(
+            parse_plaintext("X\n"),
+            Err(PatternError::InvalidToken('X'))
+        ));
+    }
+
+    #[test]
+    fn stamp_clips_cells_outside_grid() {
+        let mut grid = Grid::new(2, 2);
+        let pattern = Pattern {
+            width: 3,
+            height: 3,
+            live_cells: vec![(0, 0), (2, 2)],
+        };
+
+        stamp(&mut grid, &pattern, 0, 0);
+
+        assert!(grid.get(0, 0));
+    }
+
+    #[test]
+    fn apply_to_resets_ages_for_cells_not_in_snapshot() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(0, 0, true);
+        grid.ages[0] = 10;
+        assert_eq!(grid.age(0, 0), 10);
+
+        let snapshot = Snapshot {
+            width: 2,
+            height: 2,
+            live_cells: vec![],
+        };
+        snapshot.apply_to(&mut grid);
+
+        assert!(!grid.get(0, 0));
+        assert_eq!(grid.age(0, 0), u16::MAX);
+    }
+
+    #[test]
+    fn snapshot_save_and_load_round_trip() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(0, 0, true);
+        grid.set(1, 1, true);
+
+        let path = std::env::temp_dir().join("game_of_life_rs_test_snapshot.json");
+        let path = path.to_str().unwrap();
+
+        Snapshot::from_grid(&grid).save(path).expect("save succeeds");
+        let loaded = Snapshot::load(path).expect("load succeeds");
+        std::fs::remove_file(path).ok();
+
+        assert_eq!(loaded.width, 3);
+        assert_eq!(loaded.height, 2);
+        assert_eq!(loaded.live_cells, vec![(0, 0), (1, 1)]);
+    }
+}